@@ -1,9 +1,10 @@
 
+#[cfg(debug_assertions)]
 #[macro_export]
 macro_rules! printc {
 
     () => {
-        $println!()
+        println!()
     };
     ($val:expr $(,)?) => {
         // Use of `match` here is intentional because it affects the lifetimes
@@ -16,7 +17,174 @@ macro_rules! printc {
             }
         }
     };
+    // Same `match` technique as the single-value arm, but only prints when
+    // the value matches the given pattern - lets a hot loop be instrumented
+    // without flooding output. Built the same way `matches!` tests a value
+    // against a pattern arm. The `matches` keyword after the comma is load-
+    // bearing: a bare `$val:expr, $pat:pat` would be ambiguous with the
+    // multi-value tuple arm below (`$pat:pat` would happily bind a second
+    // value as an irrefutable pattern and silently skip evaluating it), so
+    // the pattern is marked explicitly instead of relying on the comma alone.
+    ($val:expr, matches $pat:pat $(if $guard:expr)? $(,)?) => {
+        match $val {
+            tmp => {
+                if matches!(&tmp, $pat $(if $guard)?) {
+                    println!("{} = {:#?}",
+                        stringify!($val), &tmp);
+                }
+                tmp
+            }
+        }
+    };
+    // Let the caller pick the formatter instead of being forced through
+    // pretty Debug - handy for numeric/bitfield debugging.
+    ($val:expr; :x $(,)?) => {
+        match $val {
+            tmp => {
+                println!("{} = {:x}", stringify!($val), &tmp);
+                tmp
+            }
+        }
+    };
+    ($val:expr; :X $(,)?) => {
+        match $val {
+            tmp => {
+                println!("{} = {:X}", stringify!($val), &tmp);
+                tmp
+            }
+        }
+    };
+    ($val:expr; :b $(,)?) => {
+        match $val {
+            tmp => {
+                println!("{} = {:b}", stringify!($val), &tmp);
+                tmp
+            }
+        }
+    };
+    ($val:expr; :o $(,)?) => {
+        match $val {
+            tmp => {
+                println!("{} = {:o}", stringify!($val), &tmp);
+                tmp
+            }
+        }
+    };
+    ($val:expr; :? $(,)?) => {
+        match $val {
+            tmp => {
+                println!("{} = {:?}", stringify!($val), &tmp);
+                tmp
+            }
+        }
+    };
+    ($val:expr; : $(,)?) => {
+        match $val {
+            tmp => {
+                println!("{} = {}", stringify!($val), &tmp);
+                tmp
+            }
+        }
+    };
+    // Forward a leading message the same way `panic!` dispatches between a
+    // plain `$msg:expr` form and a `$fmt:expr, $($arg:tt)+` form, then fall
+    // through to the same printing technique as the value-only arm with the
+    // message spliced in as a prefix.
+    ($msg:expr; $val:expr $(,)?) => {
+        match $val {
+            tmp => {
+                println!("{}: {} = {:#?}", $msg, stringify!($val), &tmp);
+                tmp
+            }
+        }
+    };
+    // Comma-separated `$arg:expr` fragments, unlike a bare `$($arg:tt)+`,
+    // cannot swallow the `;` terminating this arm, so the repetition isn't
+    // ambiguous against it - and expr fragments (rather than single token trees) let each arg
+    // be an arbitrary expression, e.g. `a + b` or `v.len()`.
+    ($fmt:expr $(, $arg:expr)* ; $val:expr $(,)?) => {
+        match $val {
+            tmp => {
+                println!("{}: {} = {:#?}", format_args!($fmt $(, $arg)*), stringify!($val), &tmp);
+                tmp
+            }
+        }
+    };
     ($($val:expr),+ $(,)?) => {
         ($($crate::printc!($val)),+)
-    } 
+    }
+}
+
+// In release builds `printc!` compiles down to just the value, with no
+// `println!` side effect, so calls can be left scattered through hot code
+// the way `dbg!` is during development without paying formatting/IO cost
+// in production. Every arm shape above has a matching no-op arm here.
+#[cfg(not(debug_assertions))]
+#[macro_export]
+macro_rules! printc {
+
+    () => {
+        ()
+    };
+    ($val:expr $(,)?) => {
+        $val
+    };
+    ($val:expr, matches $pat:pat $(if $guard:expr)? $(,)?) => {
+        $val
+    };
+    ($val:expr; :x $(,)?) => {
+        $val
+    };
+    ($val:expr; :X $(,)?) => {
+        $val
+    };
+    ($val:expr; :b $(,)?) => {
+        $val
+    };
+    ($val:expr; :o $(,)?) => {
+        $val
+    };
+    ($val:expr; :? $(,)?) => {
+        $val
+    };
+    ($val:expr; : $(,)?) => {
+        $val
+    };
+    ($msg:expr; $val:expr $(,)?) => {
+        $val
+    };
+    ($fmt:expr $(, $arg:expr)* ; $val:expr $(,)?) => {
+        $val
+    };
+    ($($val:expr),+ $(,)?) => {
+        ($($val),+)
+    }
+}
+
+/// Like the empty, single-value and multi-value forms of [`printc!`], but
+/// writes to stderr and prefixes each printed value with the
+/// `[file:line:column]` of the call site, mirroring the standard library's
+/// `dbg!`. Since stderr is unbuffered there is no need to flush, and debug
+/// noise stays off of stdout. Unlike `printc!`, it also prints in release
+/// builds.
+#[macro_export]
+macro_rules! eprintc {
+
+    () => {
+        eprintln!("[{}:{}:{}]", file!(), line!(), column!())
+    };
+    ($val:expr $(,)?) => {
+        // Use of `match` here is intentional because it affects the lifetimes
+        // of temporaries - https://stackoverflow.com/a/48732525/1063961
+        match $val {
+            tmp => {
+                eprintln!("[{}:{}:{}] {} = {:#?}",
+                    file!(), line!(), column!(), stringify!($val), &tmp);
+                tmp
+            }
+        }
+    };
+    ($($val:expr),+ $(,)?) => {
+        ($($crate::eprintc!($val)),+)
+    }
 }